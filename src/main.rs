@@ -4,7 +4,8 @@ extern crate lazy_static;
 use std::{collections::HashMap, process::exit};
 
 use chrono::{
-    DateTime, FixedOffset, Local, NaiveDateTime, NaiveTime, Offset, ParseResult, TimeZone, Utc,
+    DateTime, Duration, FixedOffset, Local, LocalResult, Months, NaiveDate, NaiveDateTime,
+    NaiveTime, Offset, ParseResult, TimeZone, Utc,
 };
 use chrono_tz::OffsetName;
 use clap::Parser;
@@ -15,19 +16,134 @@ struct Args {
     #[arg()]
     datetime: String,
 
-    #[arg(default_value_t = String::from("gmt"), help="Timezone to convert to")]
-    dest_tz: String,
+    #[arg(
+        num_args = 1..,
+        value_delimiter = ',',
+        default_value = "gmt",
+        help = "Timezone(s) to convert to, comma-separated or repeated (e.g. \"gmt,est,Asia/Kolkata\")"
+    )]
+    dest_tz: Vec<String>,
 
     #[arg(short, long)]
     verbose: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "prefer_earlier",
+        help = "When a local time is ambiguous due to a DST transition, use the later offset"
+    )]
+    prefer_later: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "prefer_later",
+        help = "When a local time is ambiguous due to a DST transition, use the earlier offset (default)"
+    )]
+    prefer_earlier: bool,
+
+    #[arg(
+        short = 'u',
+        long,
+        help = "Unit for a bare-integer Unix timestamp: seconds, millis, micros, or nanos (auto-detected from magnitude if omitted)"
+    )]
+    timestamp_unit: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        help = "Output format: rfc3339, rfc2822, unix, or a strftime format string (default: the converted datetime's Display)"
+    )]
+    output: Option<String>,
+
+    #[arg(
+        long,
+        help = "Full IANA zone name to prefer when a timezone abbreviation (e.g. \"cst\") is ambiguous"
+    )]
+    assume_tz: Option<String>,
 }
 
 const FORMATS: &[&str] = &["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"];
 
 static mut VERBOSE: bool = false;
 
+// Which offset to pick when a local datetime falls in a DST fold and is therefore ambiguous.
+#[derive(Clone, Copy, Debug, Default)]
+enum DstPreference {
+    #[default]
+    Earliest,
+    Latest,
+}
+
+static mut DST_PREFERENCE: DstPreference = DstPreference::Earliest;
+
+// The unit a bare-integer `datetime` argument is interpreted as.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum TimestampUnit {
+    #[default]
+    Auto,
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+static mut TIMESTAMP_UNIT: TimestampUnit = TimestampUnit::Auto;
+
+// Full IANA zone name to prefer when an abbreviation resolves to more than one timezone.
+static mut ASSUME_TZ: Option<String> = None;
+
+fn parse_timestamp_unit_flag(value: &str) -> Option<TimestampUnit> {
+    match value.to_lowercase().as_str() {
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(TimestampUnit::Seconds),
+        "ms" | "milli" | "millis" | "millisecond" | "milliseconds" => Some(TimestampUnit::Millis),
+        "us" | "micro" | "micros" | "microsecond" | "microseconds" => Some(TimestampUnit::Micros),
+        "ns" | "nano" | "nanos" | "nanosecond" | "nanoseconds" => Some(TimestampUnit::Nanos),
+        _ => None,
+    }
+}
+
+// Guesses the unit of a bare Unix timestamp from its magnitude, the way most timestamp-sniffing
+// tools do: seconds have 10 digits today, millis 13, micros 16, and nanos 19.
+fn detect_timestamp_unit(value: i64) -> TimestampUnit {
+    match value.unsigned_abs() {
+        0..=9_999_999_999 => TimestampUnit::Seconds,
+        10_000_000_000..=9_999_999_999_999 => TimestampUnit::Millis,
+        10_000_000_000_000..=9_999_999_999_999_999 => TimestampUnit::Micros,
+        _ => TimestampUnit::Nanos,
+    }
+}
+
+fn unix_timestamp_to_datetime(value: i64, unit: TimestampUnit) -> Option<DateTime<Utc>> {
+    match unit {
+        TimestampUnit::Seconds => DateTime::from_timestamp(value, 0),
+        TimestampUnit::Millis => DateTime::from_timestamp_millis(value),
+        TimestampUnit::Micros => {
+            let secs = value.div_euclid(1_000_000);
+            let micros = value.rem_euclid(1_000_000) as u32;
+            DateTime::from_timestamp(secs, micros * 1_000)
+        }
+        TimestampUnit::Nanos => {
+            let secs = value.div_euclid(1_000_000_000);
+            let nanos = value.rem_euclid(1_000_000_000) as u32;
+            DateTime::from_timestamp(secs, nanos)
+        }
+        TimestampUnit::Auto => unreachable!("auto must be resolved via detect_timestamp_unit"),
+    }
+}
+
+// Recognizes a bare integer `datetime` argument as a Unix timestamp, e.g. `dtc 1698000000 jst`.
+fn parse_unix_timestamp(datetime: &str) -> Option<DateTime<FixedOffset>> {
+    let value: i64 = datetime.trim().parse().ok()?;
+    let unit = match unsafe { TIMESTAMP_UNIT } {
+        TimestampUnit::Auto => detect_timestamp_unit(value),
+        unit => unit,
+    };
+    verbose(&format!("Interpreting {value} as a Unix timestamp in {unit:?}."));
+    unix_timestamp_to_datetime(value, unit).map(|datetime| datetime.fixed_offset())
+}
+
 lazy_static! {
-    static ref TIMEZONES_DB: HashMap<String, chrono_tz::Tz> = build_timezone_db();
+    static ref TIMEZONES_DB: HashMap<String, Vec<chrono_tz::Tz>> = build_timezone_db();
 }
 
 fn verbose(message: &str) {
@@ -37,63 +153,140 @@ fn verbose(message: &str) {
     }
 }
 
-// fn parse_with_forced_timezone(
-//     datetime: &str,
-//     timezone: chrono_tz::Tz,
-// ) -> Result<DateTime<FixedOffset>, ()> {
-//     for format in FORMATS {
-//         verbose(&format!("Trying out format {format}"));
-//         match NaiveDateTime::parse_and_remainder(datetime, format) {
-//             ParseResult::Ok((datetime, _)) => {
-//                 return Ok(datetime
-//                     .and_local_timezone(timezone)
-//                     .unwrap()
-//                     .fixed_offset());
-//             }
-//             ParseResult::Err(e) => {
-//                 verbose(&("Error: ".to_string() + &e.to_string()));
-//             }
-//         }
-//     }
-//     Err(())
-// }
-
-// fn parse(datetime: &str) -> Result<DateTime<FixedOffset>, ()> {
-//     for format in FORMATS {
-//         verbose(&format!("Trying out format {format}"));
-//         // Try parsing without timezone first and if it succeeds assume this is in UTC.
-//         match NaiveDateTime::parse_from_str(datetime, format) {
-//             ParseResult::Ok(datetime) => {
-//                 verbose("Timezone not provided in the datetime string, assuming UTC.");
-//                 return Ok(datetime.and_utc().into());
-//             }
-//             ParseResult::Err(e) => {
-//                 verbose(&("Error: ".to_string() + &e.to_string()));
-//             }
-//         }
-//
-//         let format = format!("{format} %z");
-//         verbose(&format!("Trying out format {format}"));
-//         match DateTime::parse_from_str(datetime, &format) {
-//             ParseResult::Ok(result) => {
-//                 return Ok(result);
-//             }
-//             ParseResult::Err(e) => {
-//                 verbose(&("Error: ".to_string() + &e.to_string()));
-//             }
-//         }
-//     }
-//     if let ParseResult::Ok(result) = DateTime::parse_from_rfc3339(datetime) {
-//         return Ok(result);
-//     }
-//     if let ParseResult::Ok(result) = DateTime::parse_from_rfc2822(datetime) {
-//         return Ok(result);
-//     }
-//
-//     Err(())
-// }
+// A single calendar/clock unit recognized by the relative-datetime parser, along with its
+// plural/abbreviated spellings.
+enum RelativeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+fn parse_relative_unit(word: &str) -> Option<RelativeUnit> {
+    match word {
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(RelativeUnit::Second),
+        "min" | "mins" | "minute" | "minutes" => Some(RelativeUnit::Minute),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(RelativeUnit::Hour),
+        "d" | "day" | "days" => Some(RelativeUnit::Day),
+        "w" | "week" | "weeks" => Some(RelativeUnit::Week),
+        "mo" | "month" | "months" => Some(RelativeUnit::Month),
+        "y" | "yr" | "yrs" | "year" | "years" => Some(RelativeUnit::Year),
+        _ => None,
+    }
+}
+
+// Parses `<N> <unit> (ago)?`, e.g. "3 days ago" or "2 hours", applying it to `now`.
+fn parse_relative_expression(tokens: &[&str], now: DateTime<Local>) -> Option<DateTime<Local>> {
+    let (count, unit, rest) = match tokens {
+        [count, unit, rest @ ..] => (count, unit, rest),
+        _ => return None,
+    };
+    let count: i64 = count.parse().ok()?;
+    let unit = parse_relative_unit(unit)?;
+
+    let ago = match rest {
+        [] => false,
+        ["ago"] => true,
+        _ => return None,
+    };
+    let count = if ago { count.checked_neg()? } else { count };
+
+    verbose(&format!("Applying relative offset of {count} {unit:?}(s)"));
+
+    match unit {
+        RelativeUnit::Second => Duration::try_seconds(count).and_then(|d| now.checked_add_signed(d)),
+        RelativeUnit::Minute => Duration::try_minutes(count).and_then(|d| now.checked_add_signed(d)),
+        RelativeUnit::Hour => Duration::try_hours(count).and_then(|d| now.checked_add_signed(d)),
+        RelativeUnit::Day => Duration::try_days(count).and_then(|d| now.checked_add_signed(d)),
+        RelativeUnit::Week => Duration::try_weeks(count).and_then(|d| now.checked_add_signed(d)),
+        RelativeUnit::Month => {
+            if count >= 0 {
+                now.checked_add_months(Months::new(count as u32))
+            } else {
+                now.checked_sub_months(Months::new((-count) as u32))
+            }
+        }
+        RelativeUnit::Year => {
+            if count >= 0 {
+                now.checked_add_months(Months::new(count as u32 * 12))
+            } else {
+                now.checked_sub_months(Months::new((-count) as u32 * 12))
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for RelativeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            RelativeUnit::Second => "second",
+            RelativeUnit::Minute => "minute",
+            RelativeUnit::Hour => "hour",
+            RelativeUnit::Day => "day",
+            RelativeUnit::Week => "week",
+            RelativeUnit::Month => "month",
+            RelativeUnit::Year => "year",
+        };
+        f.write_str(name)
+    }
+}
+
+// Resolves a keyword base (`today`/`tomorrow`/`yesterday`) plus an optional trailing clock time
+// (e.g. "tomorrow 15:00") to a local datetime, defaulting to midnight when no time is given.
+fn parse_keyword_with_time(tokens: &[&str], now: DateTime<Local>) -> Option<DateTime<Local>> {
+    let (date, rest) = match tokens {
+        ["today", rest @ ..] => (now.date_naive(), rest),
+        ["tomorrow", rest @ ..] => (now.date_naive() + Duration::days(1), rest),
+        ["yesterday", rest @ ..] => (now.date_naive() - Duration::days(1), rest),
+        _ => return None,
+    };
+
+    let time = if rest.is_empty() {
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    } else {
+        let joined = rest.join(" ");
+        NaiveTime::parse_from_str(&joined, "%H:%M:%S")
+            .or_else(|_| NaiveTime::parse_from_str(&joined, "%H:%M"))
+            .ok()?
+    };
+
+    match NaiveDateTime::new(date, time).and_local_timezone(Local) {
+        LocalResult::Single(datetime) => Some(datetime),
+        LocalResult::Ambiguous(earliest, _) => Some(earliest),
+        LocalResult::None => None,
+    }
+}
+
+// Pre-pass over `datetime` that recognizes relative/natural-language expressions ("now",
+// "3 hours ago", "tomorrow 09:00") before falling through to absolute parsing.
+fn parse_relative(datetime: &str) -> Option<DateTime<FixedOffset>> {
+    let lowercased = datetime.trim().to_lowercase();
+    let tokens: Vec<&str> = lowercased.split_whitespace().collect();
+    let now = Local::now();
+
+    match tokens.as_slice() {
+        ["now"] => {
+            verbose("Recognized keyword \"now\".");
+            Some(now.fixed_offset())
+        }
+        _ => parse_keyword_with_time(&tokens, now)
+            .or_else(|| parse_relative_expression(&tokens, now))
+            .map(|datetime| datetime.fixed_offset()),
+    }
+}
 
 fn parse(datetime: &str) -> Result<DateTime<FixedOffset>, ()> {
+    if let Some(datetime) = parse_unix_timestamp(datetime) {
+        return Ok(datetime);
+    }
+
+    if let Some(datetime) = parse_relative(datetime) {
+        return Ok(datetime);
+    }
+
     // Check if only time is provided, either with a timezone or not. If it is prefix it with local
     // date.
     let datetime = if NaiveTime::parse_and_remainder(datetime, "%H:%M:%S").is_ok() {
@@ -110,7 +303,74 @@ fn parse(datetime: &str) -> Result<DateTime<FixedOffset>, ()> {
     parse_datetime(&datetime)
 }
 
-fn parse_timezone(datetime: DateTime<Utc>, timezone: &str) -> Result<FixedOffset, ()> {
+// A timezone descriptor resolved from the trailing part of a datetime string: either a literal
+// numeric offset (no DST, never ambiguous) or a zone looked up by abbreviation or full IANA name
+// (may be ambiguous or nonexistent around a DST transition).
+enum ResolvedTimezone {
+    Fixed(FixedOffset),
+    Zone(chrono_tz::Tz),
+}
+
+// Why a `TIMEZONES_DB` lookup didn't resolve to exactly one zone.
+#[derive(Debug)]
+enum TimezoneLookupError {
+    NotFound,
+    Ambiguous(Vec<chrono_tz::Tz>),
+}
+
+// Looks up an abbreviation or full IANA name in `TIMEZONES_DB`. When an abbreviation maps to more
+// than one zone (e.g. "cst"), `--assume-tz` is consulted before giving up as ambiguous.
+fn resolve_timezone(query: &str) -> Result<chrono_tz::Tz, TimezoneLookupError> {
+    let candidates = TIMEZONES_DB
+        .get(&query.to_lowercase())
+        .ok_or(TimezoneLookupError::NotFound)?;
+
+    if let [timezone] = candidates.as_slice() {
+        return Ok(*timezone);
+    }
+
+    let assume_tz = unsafe { (*std::ptr::addr_of!(ASSUME_TZ)).clone() };
+    let assumed = assume_tz.and_then(|assume_tz| {
+        candidates
+            .iter()
+            .find(|timezone| timezone.name().eq_ignore_ascii_case(&assume_tz))
+            .copied()
+    });
+    if let Some(timezone) = assumed {
+        return Ok(timezone);
+    }
+
+    // The candidates disagree on IANA name (e.g. Asia/Shanghai vs. Asia/Chongqing) but may still
+    // agree on UTC offset at the reference instant (e.g. the dozens of permanently-GMT zones, or
+    // Asia/Tokyo next to its Japan alias). When they do, the distinction is immaterial for
+    // conversion purposes, so pick the first deterministically rather than forcing the user to
+    // disambiguate.
+    let reference = reference_instant();
+    let first_offset = candidates[0].from_utc_datetime(&reference).offset().fix();
+    if candidates
+        .iter()
+        .all(|candidate| candidate.from_utc_datetime(&reference).offset().fix() == first_offset)
+    {
+        return Ok(candidates[0]);
+    }
+
+    Err(TimezoneLookupError::Ambiguous(candidates.clone()))
+}
+
+fn describe_ambiguous_timezone(query: &str, candidates: &[chrono_tz::Tz]) -> String {
+    let names: Vec<&str> = candidates.iter().map(|timezone| timezone.name()).collect();
+    format!(
+        "Timezone abbreviation \"{query}\" is ambiguous between: {}. Pass the full IANA name instead, or disambiguate with --assume-tz.",
+        names.join(", ")
+    )
+}
+
+fn parse_timezone(datetime: DateTime<Utc>, timezone: &str) -> Result<ResolvedTimezone, ()> {
+    if timezone.is_empty() {
+        verbose("No timezone given; assuming UTC.");
+        return Ok(ResolvedTimezone::Fixed(Utc.fix()));
+    }
+
     let datetime_str = datetime.format("%Y-%m-%d %H:%M:%S").to_string();
     for format in &["%#z", "%:z", "%::z", "%Z"] {
         verbose(&format!("Trying out format {format}"));
@@ -119,33 +379,95 @@ fn parse_timezone(datetime: DateTime<Utc>, timezone: &str) -> Result<FixedOffset
             &format!("%Y-%m-%d %H:%M:%S {format}"),
         ) {
             ParseResult::Ok(datetime) => {
-                return Ok(*datetime.offset());
+                return Ok(ResolvedTimezone::Fixed(*datetime.offset()));
             }
             ParseResult::Err(e) => {
                 verbose(&("Error: ".to_string() + &e.to_string()));
             }
         }
     }
-    if let Some(timezone) = TIMEZONES_DB.get(&timezone.to_lowercase()) {
-        let datetime = datetime.with_timezone(timezone);
-        return Ok(datetime.with_timezone(timezone).offset().fix());
+    match resolve_timezone(timezone) {
+        Ok(timezone) => return Ok(ResolvedTimezone::Zone(timezone)),
+        Err(TimezoneLookupError::Ambiguous(candidates)) => {
+            eprintln!("{}", describe_ambiguous_timezone(timezone, &candidates));
+        }
+        Err(TimezoneLookupError::NotFound) => {}
     }
 
     Err(())
 }
 
+// Applies a resolved timezone to a naive local datetime, handling the DST-ambiguous and
+// DST-nonexistent cases instead of blindly unwrapping `LocalResult`.
+fn resolve_local_timezone(
+    naive_datetime: NaiveDateTime,
+    resolved: ResolvedTimezone,
+) -> Result<DateTime<FixedOffset>, ()> {
+    match resolved {
+        // A fixed offset has no DST transitions, so this is always `Single` in practice.
+        ResolvedTimezone::Fixed(offset) => naive_datetime.and_local_timezone(offset).single().ok_or(()),
+        ResolvedTimezone::Zone(timezone) => match naive_datetime.and_local_timezone(timezone) {
+            LocalResult::Single(datetime) => Ok(datetime.fixed_offset()),
+            LocalResult::Ambiguous(earliest, latest) => {
+                let preference = unsafe { DST_PREFERENCE };
+                verbose(&format!(
+                    "{naive_datetime} is ambiguous in {timezone} (DST fold); picking the {} offset.",
+                    match preference {
+                        DstPreference::Earliest => "earlier",
+                        DstPreference::Latest => "later",
+                    }
+                ));
+                Ok(match preference {
+                    DstPreference::Earliest => earliest,
+                    DstPreference::Latest => latest,
+                }
+                .fixed_offset())
+            }
+            LocalResult::None => {
+                eprintln!(
+                    "{naive_datetime} does not exist in {timezone}: it falls in a gap skipped by a forward DST transition."
+                );
+                Err(())
+            }
+        },
+    }
+}
+
 fn parse_datetime(datetime: &str) -> Result<DateTime<FixedOffset>, ()> {
+    verbose("Trying RFC 3339.");
+    if let ParseResult::Ok(result) = DateTime::parse_from_rfc3339(datetime) {
+        return Ok(result);
+    }
+
+    verbose("Trying RFC 2822.");
+    if let ParseResult::Ok(result) = DateTime::parse_from_rfc2822(datetime) {
+        if datetime.trim_end().ends_with("-0000") {
+            verbose("Offset \"-0000\" means \"offset unknown\" per RFC 2822; treating it as UTC.");
+        }
+        return Ok(result);
+    }
+
+    // Try each format with a permissive numeric offset appended, so "+09", "+0900" and "+09:00"
+    // all parse when the offset is embedded directly in the string.
+    for format in FORMATS {
+        for offset_format in &["%z", "%:z", "%#z"] {
+            let format_with_offset = format!("{format} {offset_format}");
+            verbose(&format!("Trying out format {format_with_offset}"));
+            if let ParseResult::Ok(result) = DateTime::parse_from_str(datetime, &format_with_offset)
+            {
+                return Ok(result);
+            }
+        }
+    }
+
+    // No offset embedded in the string; parse a naive datetime and resolve the remainder (an
+    // abbreviation or IANA name) against the timezone database.
     for format in FORMATS {
         verbose(&format!("Trying out format {format}"));
         match NaiveDateTime::parse_and_remainder(datetime, format) {
             ParseResult::Ok((datetime, remainder)) => {
-                // TODO: Use local timezone if not provided.
-                return Ok(datetime
-                    .and_local_timezone(
-                        parse_timezone(datetime.and_utc(), remainder.trim()).unwrap(),
-                    )
-                    .unwrap()
-                    .fixed_offset());
+                let resolved = parse_timezone(datetime.and_utc(), remainder.trim())?;
+                return resolve_local_timezone(datetime, resolved);
             }
             ParseResult::Err(e) => {
                 verbose(&("Error: ".to_string() + &e.to_string()));
@@ -155,18 +477,44 @@ fn parse_datetime(datetime: &str) -> Result<DateTime<FixedOffset>, ()> {
     Err(())
 }
 
-fn build_timezone_db() -> HashMap<String, chrono_tz::Tz> {
+// A fixed instant (deliberately not `Utc::now()`) used only to derive each zone's "current"
+// abbreviation and to compare candidate offsets when resolving one. Keeping this fixed means which
+// abbreviations collide, and which collisions are offset-identical aliases, no longer depends on
+// the date the binary happens to run on.
+fn reference_instant() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+// Inserts `tz` under `key`, skipping it if it's already present so a zone whose abbreviation
+// happens to equal its own full IANA name (e.g. "GMT") isn't recorded twice under the same key.
+// Distinct zones are always kept, even when they currently share an offset with another
+// candidate, so `--assume-tz` can still pick one out by its full name.
+fn insert_timezone(timezones: &mut HashMap<String, Vec<chrono_tz::Tz>>, key: String, tz: chrono_tz::Tz) {
+    let candidates = timezones.entry(key).or_default();
+    if !candidates.contains(&tz) {
+        candidates.push(tz);
+    }
+}
+
+// Indexes every zone by both its abbreviation at `reference_instant()` (which may collide, e.g.
+// "cst" for US Central/China/Cuba) and its full IANA name (unique among names, though a few such
+// as "EST", "CET" or "GMT" equal other zones' abbreviations and so share their key), collecting
+// collisions into a `Vec` instead of letting the last-inserted zone silently win.
+fn build_timezone_db() -> HashMap<String, Vec<chrono_tz::Tz>> {
     let mut timezones =
-        HashMap::<String, chrono_tz::Tz>::with_capacity(chrono_tz::TZ_VARIANTS.len());
-    let utc_now = Utc::now().naive_utc();
+        HashMap::<String, Vec<chrono_tz::Tz>>::with_capacity(chrono_tz::TZ_VARIANTS.len() * 2);
+    let reference = reference_instant();
     for tz in chrono_tz::TZ_VARIANTS {
-        timezones.insert(
-            tz.from_utc_datetime(&utc_now)
-                .offset()
-                .abbreviation()
-                .to_lowercase(),
-            tz,
-        );
+        let abbreviation = tz
+            .from_utc_datetime(&reference)
+            .offset()
+            .abbreviation()
+            .to_lowercase();
+        insert_timezone(&mut timezones, abbreviation, tz);
+        insert_timezone(&mut timezones, tz.name().to_lowercase(), tz);
     }
     timezones
 }
@@ -175,28 +523,132 @@ fn convert(datetime: &DateTime<FixedOffset>, timezone: &chrono_tz::Tz) -> DateTi
     datetime.fixed_offset().with_timezone(timezone)
 }
 
+// Renders the converted datetime per `--output`: `rfc3339`, `rfc2822`, `unix`, a raw strftime
+// format string, or (when unset) the default `Display` impl. A raw format string is user-supplied
+// and chrono's `DelayedFormat` panics on `.to_string()` if it contains an unrecognized specifier,
+// so it's written through `fmt::Write` instead and a malformed specifier is reported as an error.
+fn format_output(datetime: &DateTime<chrono_tz::Tz>, output: Option<&str>) -> Result<String, ()> {
+    match output {
+        None => Ok(datetime.to_string()),
+        Some(format) => match format.to_lowercase().as_str() {
+            "rfc3339" => Ok(datetime.to_rfc3339()),
+            "rfc2822" => Ok(datetime.to_rfc2822()),
+            "unix" => Ok(datetime.timestamp().to_string()),
+            _ => {
+                let mut rendered = String::new();
+                std::fmt::Write::write_fmt(&mut rendered, format_args!("{}", datetime.format(format)))
+                    .map_err(|_| ())?;
+                Ok(rendered)
+            }
+        },
+    }
+}
+
+// Formats one aligned row: the IANA name (padded to `name_width`), the local wall-clock time, and
+// the UTC offset. Factored out of `print_conversion_table` so it's testable without capturing
+// stdout.
+fn format_conversion_row(
+    datetime: &DateTime<FixedOffset>,
+    timezone: &chrono_tz::Tz,
+    output: Option<&str>,
+    name_width: usize,
+) -> Result<String, ()> {
+    let converted = convert(datetime, timezone);
+    Ok(format!(
+        "{:<name_width$}  {}  {}",
+        timezone.name(),
+        format_output(&converted, output)?,
+        converted.offset().fix(),
+    ))
+}
+
+// Prints one aligned row per target zone: IANA name, local wall-clock time, and UTC offset — the
+// common case of comparing the same instant across several regions at once.
+fn print_conversion_table(
+    datetime: &DateTime<FixedOffset>,
+    timezones: &[chrono_tz::Tz],
+    output: Option<&str>,
+) {
+    let name_width = timezones
+        .iter()
+        .map(|timezone| timezone.name().len())
+        .max()
+        .unwrap_or(0);
+    for timezone in timezones {
+        match format_conversion_row(datetime, timezone, output, name_width) {
+            Ok(row) => println!("{row}"),
+            Err(()) => {
+                eprintln!("Invalid --output format {output:?}");
+                exit(1);
+            }
+        }
+    }
+}
+
 fn main() {
     let args = Args::parse();
     unsafe {
         VERBOSE = args.verbose;
+        DST_PREFERENCE = if args.prefer_later {
+            DstPreference::Latest
+        } else {
+            DstPreference::Earliest
+        };
+    }
+
+    if let Some(unit) = &args.timestamp_unit {
+        match parse_timestamp_unit_flag(unit) {
+            Some(unit) => unsafe { TIMESTAMP_UNIT = unit },
+            None => {
+                eprintln!("Unknown timestamp unit {unit}");
+                exit(1);
+            }
+        }
+    }
+
+    unsafe {
+        ASSUME_TZ = args.assume_tz.clone();
     }
 
-    let dest_tz = match TIMEZONES_DB.get(&args.dest_tz.to_lowercase()) {
-        Some(tz) => tz,
-        None => {
-            eprintln!(
-                "Destination timezone {} could not be found in the timezone database",
-                args.dest_tz
-            );
+    let dest_timezones: Vec<chrono_tz::Tz> = args
+        .dest_tz
+        .iter()
+        .map(|dest_tz| match resolve_timezone(dest_tz) {
+            Ok(timezone) => timezone,
+            Err(TimezoneLookupError::NotFound) => {
+                eprintln!(
+                    "Destination timezone {dest_tz} could not be found in the timezone database"
+                );
+                exit(1);
+            }
+            Err(TimezoneLookupError::Ambiguous(candidates)) => {
+                eprintln!("{}", describe_ambiguous_timezone(dest_tz, &candidates));
+                exit(1);
+            }
+        })
+        .collect();
+
+    let datetime_parsed = match parse(&args.datetime) {
+        Ok(datetime) => datetime,
+        Err(()) => {
+            eprintln!("Could not parse {}", args.datetime);
             exit(1);
         }
     };
-
-    let datetime_parsed =
-        parse(&args.datetime).unwrap_or_else(|()| panic!("Could not parse {}", args.datetime));
     verbose(&datetime_parsed.to_string());
 
-    println!("{}", convert(&datetime_parsed, dest_tz));
+    match dest_timezones.as_slice() {
+        [dest_tz] => {
+            match format_output(&convert(&datetime_parsed, dest_tz), args.output.as_deref()) {
+                Ok(formatted) => println!("{formatted}"),
+                Err(()) => {
+                    eprintln!("Invalid --output format {:?}", args.output);
+                    exit(1);
+                }
+            }
+        }
+        targets => print_conversion_table(&datetime_parsed, targets, args.output.as_deref()),
+    }
 }
 
 #[cfg(test)]
@@ -209,6 +661,33 @@ mod tests {
         assert_eq!(datetime, parse(datetime).unwrap().to_rfc3339());
     }
 
+    #[test]
+    fn parse_rfc2822() {
+        let datetime = "Wed, 18 Feb 2015 23:16:09 -0000";
+        assert_eq!(
+            "2015-02-18T23:16:09+00:00",
+            parse(datetime).unwrap().to_rfc3339()
+        );
+    }
+
+    #[test]
+    fn parse_permissive_offset_without_colon() {
+        let datetime = "2023-10-22 10:34:16 +0900";
+        assert_eq!(
+            "2023-10-22T10:34:16+09:00",
+            parse(datetime).unwrap().to_rfc3339()
+        );
+    }
+
+    #[test]
+    fn parse_permissive_offset_hours_only() {
+        let datetime = "2023-10-22 10:34:16 +09";
+        assert_eq!(
+            "2023-10-22T10:34:16+09:00",
+            parse(datetime).unwrap().to_rfc3339()
+        );
+    }
+
     #[test]
     fn parse_timezone_abbreviation() {
         let datetime = "2023-10-22 10:34:16 jst";
@@ -227,6 +706,174 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parse_now_keyword() {
+        let before = Local::now().fixed_offset();
+        let result = parse("now").unwrap();
+        let after = Local::now().fixed_offset();
+        assert!(result >= before && result <= after);
+    }
+
+    #[test]
+    fn parse_relative_days_ago() {
+        let expected = (Local::now() - Duration::days(3)).fixed_offset();
+        let result = parse("3 days ago").unwrap();
+        assert!((result - expected).num_seconds().abs() < 2);
+    }
+
+    #[test]
+    fn parse_relative_overflow_fails_instead_of_panicking() {
+        assert!(parse("999999999999999999 days ago").is_err());
+    }
+
+    #[test]
+    fn parse_relative_i64_min_ago_fails_instead_of_panicking() {
+        assert!(parse("-9223372036854775808 days ago").is_err());
+    }
+
+    #[test]
+    fn parse_relative_without_ago() {
+        let expected = (Local::now() + Duration::hours(2)).fixed_offset();
+        let result = parse("2 hours").unwrap();
+        assert!((result - expected).num_seconds().abs() < 2);
+    }
+
+    #[test]
+    fn parse_tomorrow_with_time() {
+        let date = Local::now().date_naive() + Duration::days(1);
+        let expected = date.and_hms_opt(9, 0, 0).unwrap();
+        let result = parse("tomorrow 09:00").unwrap();
+        assert_eq!(result.naive_local(), expected);
+    }
+
+    #[test]
+    fn resolve_nonexistent_dst_gap() {
+        // 2024-03-10 02:30 America/New_York falls inside the spring-forward gap (02:00 -> 03:00).
+        let naive = NaiveDate::from_ymd_opt(2024, 3, 10)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        let result = resolve_local_timezone(
+            naive,
+            ResolvedTimezone::Zone(chrono_tz::Tz::America__New_York),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolve_ambiguous_dst_fold_prefers_earliest_by_default() {
+        // 2024-11-03 01:30 America/New_York occurs twice (fall-back fold).
+        let naive = NaiveDate::from_ymd_opt(2024, 11, 3)
+            .unwrap()
+            .and_hms_opt(1, 30, 0)
+            .unwrap();
+        let result = resolve_local_timezone(
+            naive,
+            ResolvedTimezone::Zone(chrono_tz::Tz::America__New_York),
+        )
+        .unwrap();
+        assert_eq!(result.offset().local_minus_utc(), -4 * 3600);
+    }
+
+    #[test]
+    fn parse_unix_seconds_timestamp() {
+        let result = parse("1698000000").unwrap();
+        assert_eq!("2023-10-22T18:40:00+00:00", result.to_rfc3339());
+    }
+
+    #[test]
+    fn parse_unix_millis_timestamp() {
+        let result = parse("1698000000000").unwrap();
+        assert_eq!("2023-10-22T18:40:00+00:00", result.to_rfc3339());
+    }
+
+    #[test]
+    fn format_output_variants() {
+        let datetime = convert(
+            &DateTime::parse_from_rfc3339("2023-10-22T10:34:16+00:00").unwrap(),
+            &chrono_tz::Tz::UTC,
+        );
+        assert_eq!(
+            "2023-10-22T10:34:16+00:00",
+            format_output(&datetime, Some("rfc3339")).unwrap()
+        );
+        assert_eq!(
+            "Sun, 22 Oct 2023 10:34:16 +0000",
+            format_output(&datetime, Some("rfc2822")).unwrap()
+        );
+        assert_eq!("1697970856", format_output(&datetime, Some("unix")).unwrap());
+        assert_eq!(
+            "2023-10-22",
+            format_output(&datetime, Some("%Y-%m-%d")).unwrap()
+        );
+    }
+
+    #[test]
+    fn format_output_invalid_strftime_spec_errors_instead_of_panicking() {
+        let datetime = convert(
+            &DateTime::parse_from_rfc3339("2023-10-22T10:34:16+00:00").unwrap(),
+            &chrono_tz::Tz::UTC,
+        );
+        assert!(format_output(&datetime, Some("%Q")).is_err());
+    }
+
+    #[test]
+    fn resolve_timezone_by_full_iana_name() {
+        assert_eq!(
+            resolve_timezone("Asia/Tokyo").unwrap(),
+            chrono_tz::Tz::Asia__Tokyo
+        );
+    }
+
+    #[test]
+    fn resolve_ambiguous_abbreviation_lists_candidates() {
+        // "cst" collides across multiple permanently-CST zones (e.g. China, Saskatchewan).
+        match resolve_timezone("cst") {
+            Err(TimezoneLookupError::Ambiguous(candidates)) => assert!(candidates.len() > 1),
+            Ok(_) => panic!("expected \"cst\" to be ambiguous"),
+            Err(TimezoneLookupError::NotFound) => panic!("expected \"cst\" to have candidates"),
+        }
+    }
+
+    #[test]
+    fn resolve_default_gmt_is_unambiguous() {
+        // Dozens of zones are permanently at UTC+0 under the "gmt" abbreviation; they should
+        // collapse to a single candidate instead of making the tool's own default dest-tz error.
+        assert!(resolve_timezone("gmt").is_ok());
+    }
+
+    #[test]
+    fn resolve_ambiguous_abbreviation_respects_assume_tz() {
+        unsafe {
+            ASSUME_TZ = Some("Asia/Shanghai".to_string());
+        }
+        let result = resolve_timezone("cst");
+        unsafe {
+            ASSUME_TZ = None;
+        }
+        assert_eq!(result.unwrap(), chrono_tz::Tz::Asia__Shanghai);
+    }
+
+    #[test]
+    fn format_conversion_row_includes_name_time_and_offset() {
+        let datetime = DateTime::parse_from_rfc3339("2023-10-22T10:34:16+00:00").unwrap();
+        let row =
+            format_conversion_row(&datetime, &chrono_tz::Tz::Asia__Tokyo, None, "Asia/Tokyo".len())
+                .unwrap();
+        assert!(row.starts_with("Asia/Tokyo"));
+        assert!(row.contains("19:34:16"));
+        assert!(row.contains("+09:00"));
+    }
+
+    #[test]
+    fn dest_tz_accepts_comma_separated_and_repeated_forms() {
+        let comma = Args::try_parse_from(["dtc", "now", "gmt,est,Asia/Kolkata"]).unwrap();
+        assert_eq!(comma.dest_tz, vec!["gmt", "est", "Asia/Kolkata"]);
+
+        let repeated = Args::try_parse_from(["dtc", "now", "gmt", "est", "Asia/Kolkata"]).unwrap();
+        assert_eq!(repeated.dest_tz, vec!["gmt", "est", "Asia/Kolkata"]);
+    }
+
     #[test]
     fn convert_datetime_to_utc() {
         let datetime = DateTime::parse_from_rfc3339("2023-10-22T10:34:16+02:00").unwrap();